@@ -1,5 +1,6 @@
 use criterion::*;
 use rayon::prelude::*;
+use starky::linearhash_bn128::LinearHashBN128;
 use starky::merklehash_bn128::MerkleTree;
 use winter_math::fields::f64::BaseElement;
 use winter_math::FieldElement;
@@ -10,7 +11,7 @@ fn run_merklehash(pols: Vec<BaseElement>) {
     let n_pols = 20;
 
     let now = std::time::Instant::now();
-    let tree = MerkleTree::merkelize(pols, n_pols, n).unwrap();
+    let tree = MerkleTree::<LinearHashBN128>::merkelize(pols, n_pols, n).unwrap();
     println!("time cost: {}", now.elapsed().as_secs());
     let (group_elements, mp) = tree.get_group_proof(idx).unwrap();
     let root = tree.root();
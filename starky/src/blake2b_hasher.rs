@@ -0,0 +1,191 @@
+use winter_crypto::{Digest, Hasher};
+
+use crate::digest_bn128::ElementDigest;
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const SIGMA: [[usize; 16]; 12] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+];
+
+#[inline(always)]
+fn g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+/// BLAKE2b compression function `F`, operating on a 128-byte message block.
+fn compress(h: &mut [u64; 8], block: &[u8; 128], counter: u128, last_block: bool) {
+    let mut m = [0u64; 16];
+    for (i, word) in m.iter_mut().enumerate() {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&block[i * 8..i * 8 + 8]);
+        *word = u64::from_le_bytes(buf);
+    }
+
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..16].copy_from_slice(&IV);
+    v[12] ^= counter as u64;
+    v[13] ^= (counter >> 64) as u64;
+    if last_block {
+        v[14] ^= 0xffffffffffffffff;
+    }
+
+    for sigma in SIGMA.iter() {
+        g(&mut v, 0, 4, 8, 12, m[sigma[0]], m[sigma[1]]);
+        g(&mut v, 1, 5, 9, 13, m[sigma[2]], m[sigma[3]]);
+        g(&mut v, 2, 6, 10, 14, m[sigma[4]], m[sigma[5]]);
+        g(&mut v, 3, 7, 11, 15, m[sigma[6]], m[sigma[7]]);
+        g(&mut v, 0, 5, 10, 15, m[sigma[8]], m[sigma[9]]);
+        g(&mut v, 1, 6, 11, 12, m[sigma[10]], m[sigma[11]]);
+        g(&mut v, 2, 7, 8, 13, m[sigma[12]], m[sigma[13]]);
+        g(&mut v, 3, 4, 9, 14, m[sigma[14]], m[sigma[15]]);
+    }
+
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+/// Self-contained BLAKE2b-256 (32-byte digest, unkeyed).
+fn blake2b_256(data: &[u8]) -> [u8; 32] {
+    let mut h = IV;
+    h[0] ^= 0x01010000 ^ 32u64;
+
+    let mut counter: u128 = 0;
+    let mut chunks = data.chunks(128).peekable();
+    if chunks.peek().is_none() {
+        compress(&mut h, &[0u8; 128], 0, true);
+    } else {
+        while let Some(chunk) = chunks.next() {
+            let is_last = chunks.peek().is_none();
+            counter += chunk.len() as u128;
+            let mut block = [0u8; 128];
+            block[..chunk.len()].copy_from_slice(chunk);
+            compress(&mut h, &block, counter, is_last);
+        }
+    }
+
+    let mut out = [0u8; 32];
+    for i in 0..4 {
+        out[i * 8..i * 8 + 8].copy_from_slice(&h[i].to_le_bytes());
+    }
+    out
+}
+
+/// A recursion-unfriendly but fast `Hasher` backend, for Merkle trees that are never
+/// opened inside a SNARK.
+pub struct Blake2bHasher;
+
+impl Hasher for Blake2bHasher {
+    type Digest = ElementDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        ElementDigest::from_le_bytes_mod_order(blake2b_256(bytes))
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        let mut bytes = Vec::with_capacity(64);
+        bytes.extend_from_slice(&values[0].as_bytes());
+        bytes.extend_from_slice(&values[1].as_bytes());
+        ElementDigest::from_le_bytes_mod_order(blake2b_256(&bytes))
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        let mut bytes = Vec::with_capacity(40);
+        bytes.extend_from_slice(&seed.as_bytes());
+        bytes.extend_from_slice(&value.to_le_bytes());
+        ElementDigest::from_le_bytes_mod_order(blake2b_256(&bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blake2b_256, Blake2bHasher};
+    use crate::digest_bn128::ElementDigest;
+    use winter_crypto::Hasher;
+    use winter_math::fields::f64::BaseElement;
+
+    /// BLAKE2b-256("") and BLAKE2b-256("abc"), cross-checked against a trusted
+    /// reference implementation (Python's `hashlib.blake2b(digest_size=32)`), pin down
+    /// the compression function itself (SIGMA rows, counter injection, rotations)
+    /// independently of this module's own Goldilocks wrapping.
+    #[test]
+    fn test_blake2b_256_matches_known_answer_vectors() {
+        assert_eq!(
+            blake2b_256(b""),
+            [
+                0x0e, 0x57, 0x51, 0xc0, 0x26, 0xe5, 0x43, 0xb2, 0xe8, 0xab, 0x2e, 0xb0, 0x60,
+                0x99, 0xda, 0xa1, 0xd1, 0xe5, 0xdf, 0x47, 0x77, 0x8f, 0x77, 0x87, 0xfa, 0xab,
+                0x45, 0xcd, 0xf1, 0x2f, 0xe3, 0xa8,
+            ]
+        );
+        assert_eq!(
+            blake2b_256(b"abc"),
+            [
+                0xbd, 0xdd, 0x81, 0x3c, 0x63, 0x42, 0x39, 0x72, 0x31, 0x71, 0xef, 0x3f, 0xee,
+                0x98, 0x57, 0x9b, 0x94, 0x96, 0x4e, 0x3b, 0xb1, 0xcb, 0x3e, 0x42, 0x72, 0x62,
+                0xc8, 0xc0, 0x68, 0xd5, 0x23, 0x19,
+            ]
+        );
+    }
+
+    /// Same "abc" vector, carried through `Blake2bHasher::hash`'s Goldilocks-limb
+    /// reduction, so the wrapping logic is pinned down too, not just the raw hash.
+    #[test]
+    fn test_blake2b_hasher_hash_matches_known_answer_vector() {
+        let expected = ElementDigest::new([
+            BaseElement::new(8230682787980631485),
+            BaseElement::new(11193583547894952241),
+            BaseElement::new(4773476617123960468),
+            BaseElement::new(1811526121020744306),
+        ]);
+        assert_eq!(Blake2bHasher::hash(b"abc"), expected);
+    }
+
+    #[test]
+    fn test_blake2b_256_is_deterministic_and_injective_on_length() {
+        let a = blake2b_256(b"eigen-zkvm");
+        let b = blake2b_256(b"eigen-zkvm");
+        assert_eq!(a, b);
+
+        // exercise the multi-block path: exactly one block vs spilling into a second
+        let one_block = blake2b_256(&[7u8; 128]);
+        let two_blocks = blake2b_256(&[7u8; 129]);
+        assert_ne!(one_block, two_blocks);
+    }
+
+    #[test]
+    fn test_blake2b_hasher_merge_is_order_sensitive() {
+        let a = Blake2bHasher::hash(b"left");
+        let b = Blake2bHasher::hash(b"right");
+        assert_ne!(Blake2bHasher::merge(&[a, b]), Blake2bHasher::merge(&[b, a]));
+    }
+}
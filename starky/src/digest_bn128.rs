@@ -31,8 +31,71 @@ impl ElementDigest {
         let len = digests.len() * DIGEST_SIZE;
         unsafe { slice::from_raw_parts(p as *const BaseElement, len) }
     }
+
+    /// Builds an `ElementDigest` from the 32 raw output bytes of a non-native hash
+    /// (e.g. BLAKE2b, see `Blake2bHasher`), by splitting them into four little-endian u64
+    /// limbs and reducing each modulo the Goldilocks prime.
+    pub fn from_le_bytes_mod_order(bytes: [u8; 32]) -> Self {
+        let mut limbs = [BaseElement::ZERO; DIGEST_SIZE];
+        for i in 0..DIGEST_SIZE {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&bytes[i * 8..i * 8 + 8]);
+            let v = u64::from_le_bytes(buf) % GOLDILOCKS_MODULUS;
+            limbs[i] = BaseElement::new(v);
+        }
+        Self(limbs)
+    }
+
+    /// Serializes the digest as a single 256-bit big-endian integer, limb 3 first,
+    /// matching how an EVM contract lays out a `bytes32`/`uint256` word.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for i in 0..DIGEST_SIZE {
+            let limb_start = (DIGEST_SIZE - 1 - i) * 8;
+            out[limb_start..limb_start + 8].copy_from_slice(&self.0[i].as_int().to_be_bytes());
+        }
+        out
+    }
+
+    /// Inverse of [`Self::to_be_bytes`], reducing each 64-bit limb modulo the
+    /// Goldilocks prime.
+    pub fn from_be_bytes_mod_order(bytes: [u8; 32]) -> Self {
+        let mut limbs = [BaseElement::ZERO; DIGEST_SIZE];
+        for i in 0..DIGEST_SIZE {
+            let mut buf = [0u8; 8];
+            let limb_start = (DIGEST_SIZE - 1 - i) * 8;
+            buf.copy_from_slice(&bytes[limb_start..limb_start + 8]);
+            let v = u64::from_be_bytes(buf) % GOLDILOCKS_MODULUS;
+            limbs[i] = BaseElement::new(v);
+        }
+        Self(limbs)
+    }
+
+    /// RLP-encodes the digest as a 32-byte string (`0xa0` followed by its big-endian
+    /// bytes), for embedding a Merkle opening in Ethereum calldata.
+    pub fn to_rlp(&self) -> Vec<u8> {
+        crate::rlp::encode_string(&self.to_be_bytes())
+    }
+
+    /// Decodes a digest previously produced by [`Self::to_rlp`], rejecting trailing
+    /// bytes or a payload that isn't exactly 32 bytes.
+    pub fn from_rlp(bytes: &[u8]) -> crate::errors::Result<Self> {
+        match crate::rlp::decode(bytes)? {
+            crate::rlp::Item::String(s) if s.len() == 32 => {
+                let mut buf = [0u8; 32];
+                buf.copy_from_slice(&s);
+                Ok(Self::from_be_bytes_mod_order(buf))
+            }
+            _ => Err(crate::errors::EigenError::RlpError(
+                "expected a 32-byte digest".to_string(),
+            )),
+        }
+    }
 }
 
+/// Goldilocks prime `2^64 - 2^32 + 1`.
+pub(crate) const GOLDILOCKS_MODULUS: u64 = 0xFFFFFFFF00000001;
+
 /// Field mapping
 /// Fr always consists of [u64; limbs], here for bn128, the limbs is 4.
 impl From<&Fr> for ElementDigest {
@@ -0,0 +1,247 @@
+use rayon::prelude::*;
+use winter_math::fields::f64::BaseElement;
+use winter_math::{FieldElement, StarkField};
+
+use crate::errors::{EigenError, Result};
+
+fn reverse_bits(mut x: usize, bits: u32) -> usize {
+    let mut r = 0usize;
+    for _ in 0..bits {
+        r = (r << 1) | (x & 1);
+        x >>= 1;
+    }
+    r
+}
+
+fn bit_reverse_permute(values: &mut [BaseElement]) {
+    let n = values.len();
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = reverse_bits(i, log_n);
+        if j > i {
+            values.swap(i, j);
+        }
+    }
+}
+
+fn precompute_twiddles(root: BaseElement, half: usize) -> Vec<BaseElement> {
+    let mut twiddles = vec![BaseElement::ONE; half];
+    for i in 1..half {
+        twiddles[i] = twiddles[i - 1] * root;
+    }
+    twiddles
+}
+
+/// A radix-2 evaluation domain of size `2^log_size` over the Goldilocks field
+/// (`winter_math::fields::f64::BaseElement`, 2-adicity 32), supporting sizes up to
+/// `2^32`. Twiddle factors are precomputed once and reused by every `fft`/`ifft` call
+/// on the domain.
+pub struct EvaluationDomain {
+    size: usize,
+    log_size: u32,
+    size_inv: BaseElement,
+    twiddles: Vec<BaseElement>,
+    twiddles_inv: Vec<BaseElement>,
+}
+
+impl EvaluationDomain {
+    pub fn new(size: usize) -> Result<Self> {
+        if size == 0 || !size.is_power_of_two() {
+            return Err(EigenError::FftError(format!(
+                "domain size {} is not a power of two",
+                size
+            )));
+        }
+        let log_size = size.trailing_zeros();
+        if log_size > BaseElement::TWO_ADICITY {
+            return Err(EigenError::FftError(format!(
+                "domain size 2^{} exceeds the field's 2-adicity of {}",
+                log_size,
+                BaseElement::TWO_ADICITY
+            )));
+        }
+
+        let omega = BaseElement::get_root_of_unity(log_size);
+        let omega_inv = omega.inv();
+        let size_inv = BaseElement::from(size as u64).inv();
+        let half = size / 2;
+
+        Ok(Self {
+            size,
+            log_size,
+            size_inv,
+            twiddles: precompute_twiddles(omega, half),
+            twiddles_inv: precompute_twiddles(omega_inv, half),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// In-place forward NTT: coefficients -> evaluations.
+    pub fn fft(&self, values: &mut [BaseElement]) -> Result<()> {
+        self.transform(values, &self.twiddles, false)
+    }
+
+    /// In-place inverse NTT: evaluations -> coefficients.
+    pub fn ifft(&self, values: &mut [BaseElement]) -> Result<()> {
+        self.transform(values, &self.twiddles_inv, true)
+    }
+
+    /// Forward NTT over the coset `shift * H`: scales coefficient `i` by `shift^i`
+    /// before transforming.
+    pub fn coset_fft(&self, values: &mut [BaseElement], shift: BaseElement) -> Result<()> {
+        scale_by_powers(values, shift);
+        self.fft(values)
+    }
+
+    /// Inverse of [`Self::coset_fft`]: transforms back to coefficient form, then
+    /// unscales coefficient `i` by `shift^-i`.
+    pub fn coset_ifft(&self, values: &mut [BaseElement], shift: BaseElement) -> Result<()> {
+        self.ifft(values)?;
+        scale_by_powers(values, shift.inv());
+        Ok(())
+    }
+
+    /// Iterative in-place radix-2 decimation-in-time (I)NTT, bit-reverse permuting
+    /// first and then combining butterflies stage by stage. The outer stage loop is
+    /// sequential (each stage depends on the previous one), but within a stage every
+    /// butterfly touches a disjoint pair of elements, so all `size/2` of them for that
+    /// stage run in parallel with rayon regardless of how many blocks the stage has
+    /// (the last stage is a single, size/2-butterfly block, and is the most expensive
+    /// one to get right).
+    fn transform(&self, values: &mut [BaseElement], twiddles: &[BaseElement], inverse: bool) -> Result<()> {
+        if values.len() != self.size {
+            return Err(EigenError::FftError(format!(
+                "expected {} values, got {}",
+                self.size,
+                values.len()
+            )));
+        }
+
+        bit_reverse_permute(values);
+
+        for s in 1..=self.log_size {
+            let len = 1usize << s;
+            let half_len = len / 2;
+            let stride = self.size / len;
+            run_stage(values, twiddles, len, half_len, stride);
+        }
+
+        if inverse {
+            let size_inv = self.size_inv;
+            values.par_iter_mut().for_each(|v| *v *= size_inv);
+        }
+
+        Ok(())
+    }
+}
+
+/// A `*mut BaseElement` wrapper, `Send`/`Sync` because every rayon task below only
+/// ever touches the two indices its own butterfly owns.
+struct RawValues(*mut BaseElement);
+unsafe impl Send for RawValues {}
+unsafe impl Sync for RawValues {}
+
+/// Runs every butterfly of one NTT stage in parallel. A stage of block length `len`
+/// has `values.len()/len` blocks of `half_len = len/2` butterflies each; butterfly `t`
+/// (out of `values.len()/2` total, flattened across blocks) owns the disjoint pair
+/// `(low, low + half_len)` where `low = (t / half_len) * len + (t % half_len)`, so no
+/// two butterflies in the same stage ever alias.
+fn run_stage(
+    values: &mut [BaseElement],
+    twiddles: &[BaseElement],
+    len: usize,
+    half_len: usize,
+    stride: usize,
+) {
+    let num_butterflies = values.len() / 2;
+    let raw = RawValues(values.as_mut_ptr());
+    (0..num_butterflies).into_par_iter().for_each(|t| {
+        let block = t / half_len;
+        let k = t % half_len;
+        let low = block * len + k;
+        let high = low + half_len;
+        let w = twiddles[k * stride];
+        // SAFETY: `low` and `high` are distinct across every `t` in this stage (see
+        // doc comment above), so concurrent writes through `raw.0` never alias.
+        unsafe {
+            let u = *raw.0.add(low);
+            let wt = w * *raw.0.add(high);
+            *raw.0.add(low) = u + wt;
+            *raw.0.add(high) = u - wt;
+        }
+    });
+}
+
+fn scale_by_powers(values: &mut [BaseElement], base: BaseElement) {
+    let mut power = BaseElement::ONE;
+    for v in values.iter_mut() {
+        *v *= power;
+        power *= base;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EvaluationDomain;
+    use winter_math::fields::f64::BaseElement;
+    use winter_math::{FieldElement, StarkField};
+
+    #[test]
+    fn test_fft_ifft_round_trip() {
+        let domain = EvaluationDomain::new(16).unwrap();
+        let original: Vec<BaseElement> = (0..16u64).map(BaseElement::from).collect();
+
+        let mut values = original.clone();
+        domain.fft(&mut values).unwrap();
+        domain.ifft(&mut values).unwrap();
+
+        assert_eq!(values, original);
+    }
+
+    #[test]
+    fn test_coset_fft_ifft_round_trip() {
+        let domain = EvaluationDomain::new(8).unwrap();
+        let original: Vec<BaseElement> = (0..8u64).map(BaseElement::from).collect();
+        let shift = BaseElement::from(7u64);
+
+        let mut values = original.clone();
+        domain.coset_fft(&mut values, shift).unwrap();
+        domain.coset_ifft(&mut values, shift).unwrap();
+
+        assert_eq!(values, original);
+    }
+
+    #[test]
+    fn test_new_rejects_non_power_of_two() {
+        assert!(EvaluationDomain::new(6).is_err());
+    }
+
+    /// `fft`/`ifft` inverting each other only proves the pair is *some* invertible
+    /// linear map, not that it's the correct NTT — a wrong twiddle table or a bad
+    /// stage/stride wiring would still round-trip. Check `fft`'s output directly
+    /// against the textbook definition `y_i = sum_j c_j * omega^{i*j}`.
+    #[test]
+    fn test_fft_matches_naive_dft_definition() {
+        let domain = EvaluationDomain::new(8).unwrap();
+        let coeffs: Vec<BaseElement> = (1..=8u64).map(BaseElement::from).collect();
+
+        let mut values = coeffs.clone();
+        domain.fft(&mut values).unwrap();
+
+        let omega = BaseElement::get_root_of_unity(3); // log2(8)
+        let mut xi = BaseElement::ONE;
+        for expected_i in values.iter() {
+            let mut naive = BaseElement::ZERO;
+            let mut power = BaseElement::ONE;
+            for c in coeffs.iter() {
+                naive += *c * power;
+                power *= xi;
+            }
+            assert_eq!(*expected_i, naive);
+            xi *= omega;
+        }
+    }
+}
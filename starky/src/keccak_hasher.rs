@@ -0,0 +1,155 @@
+use tiny_keccak::{Hasher as TinyKeccak, Keccak};
+use winter_crypto::{Digest, Hasher};
+use winter_math::fields::f64::BaseElement;
+use winter_math::StarkField;
+use winter_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use crate::errors::Result;
+
+fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(bytes);
+    let mut out = [0u8; 32];
+    hasher.finalize(&mut out);
+    out
+}
+
+/// A raw 32-byte Keccak-256 output, kept as-is with no reduction into the Goldilocks
+/// field. Unlike `ElementDigest`, this is *not* interpreted as field elements: folding
+/// a Keccak output through a Goldilocks reduction would diverge from what a Solidity
+/// verifier computes (plain `keccak256`) whenever a limb lands at or above the
+/// Goldilocks prime, which would silently break the "recompute on-chain" guarantee
+/// this hasher exists for.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Hash)]
+pub struct KeccakDigest([u8; 32]);
+
+impl KeccakDigest {
+    pub fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl Digest for KeccakDigest {
+    fn as_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl Serializable for KeccakDigest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8_slice(&self.0);
+    }
+}
+
+impl Deserializable for KeccakDigest {
+    fn read_from<R: ByteReader>(source: &mut R) -> std::result::Result<Self, DeserializationError> {
+        let mut bytes = [0u8; 32];
+        for byte in bytes.iter_mut() {
+            *byte = source.read_u8()?;
+        }
+        Ok(Self(bytes))
+    }
+}
+
+/// A `Hasher` backend whose leaf/node encoding mirrors a Solidity `MerkleProof`-style
+/// verifier: every field element is packed as a 32-byte big-endian word, exactly the
+/// way the EVM ABI encodes a `uint256`, before being fed to Keccak-256, and every node
+/// digest is the raw `keccak256` output (see `KeccakDigest`) so a contract doing
+/// `keccak256(abi.encodePacked(left, right))` reproduces the same root bit-for-bit.
+pub struct Keccak256Hasher;
+
+impl Keccak256Hasher {
+    /// Hashes a leaf's row of `BaseElement`s the way `abi.encodePacked` would: each
+    /// element as a 32-byte big-endian word, concatenated and hashed once.
+    pub fn hash_element_matrix(columns: &[Vec<BaseElement>]) -> Result<KeccakDigest> {
+        let mut bytes = Vec::new();
+        for col in columns.iter() {
+            for elem in col.iter() {
+                let mut word = [0u8; 32];
+                word[24..].copy_from_slice(&elem.as_int().to_be_bytes());
+                bytes.extend_from_slice(&word);
+            }
+        }
+        Ok(KeccakDigest::new(keccak256(&bytes)))
+    }
+}
+
+impl Hasher for Keccak256Hasher {
+    type Digest = KeccakDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        let elems: &[BaseElement] = unsafe { BaseElement::bytes_as_elements(bytes).unwrap() };
+        Self::hash_element_matrix(&[elems.to_vec()]).unwrap()
+    }
+
+    /// Returns `keccak256(abi.encodePacked(left, right))`: both node words are the raw
+    /// big-endian Keccak output of the child digests, exactly as `abi.encodePacked`
+    /// concatenates two `bytes32`, so a Rust-generated Merkle path verifies identically
+    /// in a Solidity `MerkleProof`-style contract.
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        let mut bytes = Vec::with_capacity(64);
+        bytes.extend_from_slice(&values[0].as_bytes());
+        bytes.extend_from_slice(&values[1].as_bytes());
+        KeccakDigest::new(keccak256(&bytes))
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        let mut bytes = Vec::with_capacity(40);
+        bytes.extend_from_slice(&seed.as_bytes());
+        bytes.extend_from_slice(&value.to_be_bytes());
+        KeccakDigest::new(keccak256(&bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{keccak256, Keccak256Hasher};
+    use crate::merklehash_bn128::MerkleTree;
+    use winter_crypto::{Digest, Hasher};
+    use winter_math::fields::f64::BaseElement;
+
+    /// `keccak256("")`, a well-known cross-implementation test vector (it's the hash
+    /// Ethereum uses for an account's empty code/storage), pins the underlying
+    /// primitive down independently of this module's own wrapping.
+    #[test]
+    fn test_keccak256_matches_known_answer_vector() {
+        let expected = [
+            0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7,
+            0x03, 0xc0, 0xe5, 0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04,
+            0x5d, 0x85, 0xa4, 0x70,
+        ];
+        assert_eq!(keccak256(&[]), expected);
+    }
+
+    #[test]
+    fn test_merge_matches_solidity_abi_encode_packed() {
+        // left = keccak256("a"), right = keccak256("b"); a Solidity contract would
+        // compute this node as keccak256(abi.encodePacked(left, right)) over the raw
+        // bytes32 words, with no field reduction anywhere in the path.
+        let left = keccak256(b"a");
+        let right = keccak256(b"b");
+        let mut packed = Vec::with_capacity(64);
+        packed.extend_from_slice(&left);
+        packed.extend_from_slice(&right);
+        let expected = keccak256(&packed);
+
+        let node = Keccak256Hasher::merge(&[super::KeccakDigest::new(left), super::KeccakDigest::new(right)]);
+        assert_eq!(node.as_bytes(), expected);
+    }
+
+    #[test]
+    fn test_keccak_group_proof_round_trips() {
+        let n: usize = 8;
+        let n_pols: usize = 2;
+        let pols: Vec<BaseElement> = (0..n * n_pols)
+            .map(|e| BaseElement::from(e as u64))
+            .collect();
+
+        let tree = MerkleTree::<Keccak256Hasher>::merkelize(pols, n_pols, n).unwrap();
+        let root = tree.root();
+        let (group_elements, mp) = tree.get_group_proof(3).unwrap();
+        assert!(tree
+            .verify_group_proof(&root, &mp, 3, &group_elements)
+            .unwrap());
+    }
+}
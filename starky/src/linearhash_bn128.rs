@@ -90,16 +90,22 @@ impl Hasher for LinearHashBN128 {
 
     /// Returns hash(`seed` || `value`). This method is intended for use in PRNG and PoW contexts.
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
-        panic!("Unimplemented method");
-        ElementDigest::default()
+        let hasher = Poseidon::new();
+        let seed_fr: Fr = seed.into();
+        let value_fr = Fr::from_str(&value.to_string()).unwrap();
+        let init_state = Fr::zero();
+        let inp = vec![seed_fr, value_fr];
+        Self::Digest::from(&hasher.hash(&inp, &init_state).unwrap())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::digest_bn128::ElementDigest;
     use crate::linearhash_bn128::LinearHashBN128;
     use crate::poseidon_bn128::{Fr, Poseidon};
     use ff::*;
+    use winter_crypto::Hasher;
     use winter_math::fields::f64::BaseElement;
     use winter_math::StarkField;
 
@@ -123,4 +129,14 @@ mod tests {
             "Fr(0x29c2ac38b7b8d18b9c1b575369cb4ab930ef71ebd5e4631b3916360233a29cae)",
         );
     }
+
+    #[test]
+    fn test_merge_with_int_is_deterministic_and_distinct() {
+        let seed = ElementDigest::default();
+        let a = LinearHashBN128::merge_with_int(seed, 1);
+        let b = LinearHashBN128::merge_with_int(seed, 1);
+        let c = LinearHashBN128::merge_with_int(seed, 2);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
 }
\ No newline at end of file
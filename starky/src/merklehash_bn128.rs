@@ -0,0 +1,174 @@
+use std::marker::PhantomData;
+
+use rayon::prelude::*;
+use winter_crypto::{Digest, Hasher};
+use winter_math::fields::f64::BaseElement;
+use winter_math::StarkField;
+
+use crate::errors::{EigenError, Result};
+use crate::linearhash_bn128::LinearHashBN128;
+
+/// Serialize a row of field elements the same way `LinearHashBN128::hash` expects its
+/// input bytes: each element as its canonical little-endian u64 representation.
+fn elements_to_bytes(elements: &[BaseElement]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(elements.len() * 8);
+    for e in elements.iter() {
+        bytes.extend_from_slice(&e.as_int().to_le_bytes());
+    }
+    bytes
+}
+
+/// A Merkle tree over columns of `BaseElement`s, generic over the leaf/node `Hasher`
+/// backend. `pols` is laid out column-major: column `i` occupies `pols[i*n..i*n+n]`,
+/// and row `j` (the leaf at index `j`) is formed from `pols[i*n+j]` for `i in 0..n_pols`.
+///
+/// The backend defaults to `LinearHashBN128` (Poseidon over BN128) to preserve the
+/// existing recursion-friendly behavior; pick a different `H` (e.g. `Blake2bHasher` or
+/// `Keccak256Hasher`) when the tree never needs to be opened inside a SNARK. Node
+/// digests are stored as `H::Digest` rather than a fixed `ElementDigest`, so a backend
+/// like `Keccak256Hasher` can keep its raw `keccak256` output instead of being forced
+/// through a Goldilocks-field reduction that an EVM verifier has no way to replicate.
+pub struct MerkleTree<H: Hasher = LinearHashBN128> {
+    elements: Vec<BaseElement>,
+    nodes: Vec<H::Digest>,
+    n: usize,
+    n_pols: usize,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> MerkleTree<H> {
+    /// Builds the tree from `n_pols` columns of `n` elements each, stored contiguously
+    /// in `pols`.
+    pub fn merkelize(pols: Vec<BaseElement>, n_pols: usize, n: usize) -> Result<Self> {
+        if pols.len() != n_pols * n {
+            return Err(EigenError::MerkleTreeError(format!(
+                "pols.len() {} != n_pols {} * n {}",
+                pols.len(),
+                n_pols,
+                n
+            )));
+        }
+
+        let mut leaves = vec![H::Digest::default(); n];
+        leaves.par_iter_mut().enumerate().for_each(|(j, leaf)| {
+            let mut row = Vec::with_capacity(n_pols);
+            for i in 0..n_pols {
+                row.push(pols[i * n + j]);
+            }
+            *leaf = H::hash(&elements_to_bytes(&row));
+        });
+
+        let mut nodes = leaves;
+        let mut level_start = 0;
+        let mut level_len = n;
+        while level_len > 1 {
+            let next_len = (level_len + 1) / 2;
+            let mut next_level = Vec::with_capacity(next_len);
+            for i in 0..next_len {
+                let left = nodes[level_start + 2 * i];
+                let right = if 2 * i + 1 < level_len {
+                    nodes[level_start + 2 * i + 1]
+                } else {
+                    left
+                };
+                next_level.push(H::merge(&[left, right]));
+            }
+            nodes.extend(next_level);
+            level_start += level_len;
+            level_len = next_len;
+        }
+
+        Ok(Self {
+            elements: pols,
+            nodes,
+            n,
+            n_pols,
+            _hasher: PhantomData,
+        })
+    }
+
+    pub fn root(&self) -> H::Digest {
+        self.nodes[self.nodes.len() - 1]
+    }
+
+    /// Returns the `n_pols` row elements at `idx` together with the sibling digests
+    /// needed to recompute the root, ordered from the leaf level up to the root.
+    pub fn get_group_proof(&self, idx: usize) -> Result<(Vec<BaseElement>, Vec<H::Digest>)> {
+        if idx >= self.n {
+            return Err(EigenError::MerkleTreeError(format!(
+                "idx {} out of range, n = {}",
+                idx, self.n
+            )));
+        }
+
+        let mut group_elements = Vec::with_capacity(self.n_pols);
+        for i in 0..self.n_pols {
+            group_elements.push(self.elements[i * self.n + idx]);
+        }
+
+        let mut mp = Vec::new();
+        let mut level_start = 0;
+        let mut level_len = self.n;
+        let mut pos = idx;
+        while level_len > 1 {
+            let sibling_pos = pos ^ 1;
+            let sibling = if sibling_pos < level_len {
+                self.nodes[level_start + sibling_pos]
+            } else {
+                self.nodes[level_start + pos]
+            };
+            mp.push(sibling);
+            level_start += level_len;
+            level_len = (level_len + 1) / 2;
+            pos /= 2;
+        }
+
+        Ok((group_elements, mp))
+    }
+
+    pub fn verify_group_proof(
+        &self,
+        root: &H::Digest,
+        mp: &[H::Digest],
+        idx: usize,
+        group_elements: &[BaseElement],
+    ) -> Result<bool> {
+        let mut node = H::hash(&elements_to_bytes(group_elements));
+        let mut pos = idx;
+        for sibling in mp.iter() {
+            node = if pos % 2 == 0 {
+                H::merge(&[node, *sibling])
+            } else {
+                H::merge(&[*sibling, node])
+            };
+            pos /= 2;
+        }
+
+        Ok(node.as_bytes() == root.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MerkleTree;
+    use crate::blake2b_hasher::Blake2bHasher;
+    use winter_math::fields::f64::BaseElement;
+
+    #[test]
+    fn test_merklehash_blake2b_group_proof() {
+        let n: usize = 16;
+        let n_pols: usize = 3;
+        let pols: Vec<BaseElement> = (0..n * n_pols)
+            .map(|e| BaseElement::from(e as u64))
+            .collect();
+
+        let tree = MerkleTree::<Blake2bHasher>::merkelize(pols, n_pols, n).unwrap();
+        let root = tree.root();
+        for idx in 0..n {
+            let (group_elements, mp) = tree.get_group_proof(idx).unwrap();
+            assert!(tree
+                .verify_group_proof(&root, &mp, idx, &group_elements)
+                .unwrap());
+        }
+    }
+}
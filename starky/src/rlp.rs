@@ -0,0 +1,252 @@
+//! A small, self-contained RLP encoder/decoder, just enough of Ethereum's Recursive
+//! Length Prefix scheme to pack `ElementDigest`s and Merkle group proofs into calldata.
+
+use winter_math::fields::f64::BaseElement;
+use winter_math::StarkField;
+
+use crate::digest_bn128::{ElementDigest, GOLDILOCKS_MODULUS};
+use crate::errors::{EigenError, Result};
+
+/// A decoded RLP value: either a string (byte array) or a list of items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    String(Vec<u8>),
+    List(Vec<Item>),
+}
+
+fn be_trimmed(mut v: u64) -> Vec<u8> {
+    if v == 0 {
+        return vec![0];
+    }
+    let mut bytes = Vec::new();
+    while v > 0 {
+        bytes.push((v & 0xff) as u8);
+        v >>= 8;
+    }
+    bytes.reverse();
+    bytes
+}
+
+fn be_to_u64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0u64, |acc, b| (acc << 8) | (*b as u64))
+}
+
+/// Encodes a byte string.
+pub fn encode_string(bytes: &[u8]) -> Vec<u8> {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        return vec![bytes[0]];
+    }
+    let mut out = Vec::with_capacity(bytes.len() + 9);
+    if bytes.len() <= 55 {
+        out.push(0x80 + bytes.len() as u8);
+    } else {
+        let len = be_trimmed(bytes.len() as u64);
+        out.push(0xb7 + len.len() as u8);
+        out.extend_from_slice(&len);
+    }
+    out.extend_from_slice(bytes);
+    out
+}
+
+/// Encodes a list from its already RLP-encoded items.
+pub fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload_len: usize = items.iter().map(|i| i.len()).sum();
+    let mut out = Vec::with_capacity(payload_len + 9);
+    if payload_len <= 55 {
+        out.push(0xc0 + payload_len as u8);
+    } else {
+        let len = be_trimmed(payload_len as u64);
+        out.push(0xf7 + len.len() as u8);
+        out.extend_from_slice(&len);
+    }
+    for item in items {
+        out.extend_from_slice(item);
+    }
+    out
+}
+
+fn overflow_err() -> EigenError {
+    EigenError::RlpError("RLP length overflow".to_string())
+}
+
+fn checked_add(a: usize, b: usize) -> Result<usize> {
+    a.checked_add(b).ok_or_else(overflow_err)
+}
+
+fn decode_item(input: &[u8]) -> Result<(Item, usize)> {
+    let prefix = *input
+        .first()
+        .ok_or_else(|| EigenError::RlpError("unexpected end of input".to_string()))?;
+    match prefix {
+        0x00..=0x7f => Ok((Item::String(vec![prefix]), 1)),
+        0x80..=0xb7 => {
+            let len = (prefix - 0x80) as usize;
+            let body = slice_checked(input, 1, len)?;
+            Ok((Item::String(body.to_vec()), checked_add(1, len)?))
+        }
+        0xb8..=0xbf => {
+            let len_of_len = (prefix - 0xb7) as usize;
+            let len = be_to_u64(slice_checked(input, 1, len_of_len)?) as usize;
+            let start = checked_add(1, len_of_len)?;
+            let body = slice_checked(input, start, len)?;
+            Ok((Item::String(body.to_vec()), checked_add(start, len)?))
+        }
+        0xc0..=0xf7 => {
+            let len = (prefix - 0xc0) as usize;
+            let body = slice_checked(input, 1, len)?;
+            let items = decode_items_exact(body)?;
+            Ok((Item::List(items), checked_add(1, len)?))
+        }
+        0xf8..=0xff => {
+            let len_of_len = (prefix - 0xf7) as usize;
+            let len = be_to_u64(slice_checked(input, 1, len_of_len)?) as usize;
+            let start = checked_add(1, len_of_len)?;
+            let body = slice_checked(input, start, len)?;
+            let items = decode_items_exact(body)?;
+            Ok((Item::List(items), checked_add(start, len)?))
+        }
+    }
+}
+
+fn slice_checked(input: &[u8], start: usize, len: usize) -> Result<&[u8]> {
+    let end = checked_add(start, len)?;
+    input
+        .get(start..end)
+        .ok_or_else(|| EigenError::RlpError("truncated RLP input".to_string()))
+}
+
+fn decode_items_exact(mut input: &[u8]) -> Result<Vec<Item>> {
+    let mut items = Vec::new();
+    while !input.is_empty() {
+        let (item, consumed) = decode_item(input)?;
+        items.push(item);
+        input = &input[consumed..];
+    }
+    Ok(items)
+}
+
+/// Decodes a single top-level RLP item, rejecting any trailing bytes.
+pub fn decode(input: &[u8]) -> Result<Item> {
+    let (item, consumed) = decode_item(input)?;
+    if consumed != input.len() {
+        return Err(EigenError::RlpError(
+            "trailing bytes after RLP item".to_string(),
+        ));
+    }
+    Ok(item)
+}
+
+fn encode_base_element(e: &BaseElement) -> Vec<u8> {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&e.as_int().to_be_bytes());
+    encode_string(&word)
+}
+
+fn decode_base_element(item: &Item) -> Result<BaseElement> {
+    let bytes = match item {
+        Item::String(s) if s.len() <= 32 => s,
+        _ => return Err(EigenError::RlpError("expected a field element word".to_string())),
+    };
+    let mut word = [0u8; 32];
+    word[32 - bytes.len()..].copy_from_slice(bytes);
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&word[24..32]);
+    Ok(BaseElement::new(u64::from_be_bytes(buf) % GOLDILOCKS_MODULUS))
+}
+
+fn decode_digest(item: &Item) -> Result<ElementDigest> {
+    match item {
+        Item::String(s) if s.len() == 32 => {
+            let mut buf = [0u8; 32];
+            buf.copy_from_slice(s);
+            Ok(ElementDigest::from_be_bytes_mod_order(buf))
+        }
+        _ => Err(EigenError::RlpError("expected a 32-byte digest".to_string())),
+    }
+}
+
+/// Encodes a `(group_elements, merkle_path)` opening as produced by
+/// `MerkleTree::get_group_proof`: a list of the element words followed by a nested
+/// list of the sibling digests.
+pub fn encode_group_proof(group_elements: &[BaseElement], merkle_path: &[ElementDigest]) -> Vec<u8> {
+    let mut items: Vec<Vec<u8>> = group_elements.iter().map(encode_base_element).collect();
+    let siblings: Vec<Vec<u8>> = merkle_path.iter().map(|d| d.to_rlp()).collect();
+    items.push(encode_list(&siblings));
+    encode_list(&items)
+}
+
+/// Inverse of [`encode_group_proof`].
+pub fn decode_group_proof(bytes: &[u8]) -> Result<(Vec<BaseElement>, Vec<ElementDigest>)> {
+    let items = match decode(bytes)? {
+        Item::List(items) => items,
+        _ => return Err(EigenError::RlpError("expected a list".to_string())),
+    };
+    let (sibling_list, element_items) = items
+        .split_last()
+        .ok_or_else(|| EigenError::RlpError("empty group proof".to_string()))?;
+    let siblings = match sibling_list {
+        Item::List(siblings) => siblings,
+        _ => return Err(EigenError::RlpError("expected sibling list".to_string())),
+    };
+    let group_elements = element_items
+        .iter()
+        .map(decode_base_element)
+        .collect::<Result<Vec<_>>>()?;
+    let merkle_path = siblings.iter().map(decode_digest).collect::<Result<Vec<_>>>()?;
+    Ok((group_elements, merkle_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, decode_group_proof, encode_group_proof, Item};
+    use crate::digest_bn128::ElementDigest;
+    use winter_math::fields::f64::BaseElement;
+
+    #[test]
+    fn test_digest_rlp_round_trip() {
+        let digest = ElementDigest::new([
+            BaseElement::from(1u64),
+            BaseElement::from(2u64),
+            BaseElement::from(3u64),
+            BaseElement::from(4u64),
+        ]);
+        let encoded = digest.to_rlp();
+        assert_eq!(encoded[0], 0xa0);
+        assert_eq!(encoded.len(), 33);
+        assert_eq!(ElementDigest::from_rlp(&encoded).unwrap(), digest);
+    }
+
+    #[test]
+    fn test_digest_rlp_rejects_trailing_bytes() {
+        let digest = ElementDigest::new([BaseElement::ZERO; 4]);
+        let mut encoded = digest.to_rlp();
+        encoded.push(0x00);
+        assert!(ElementDigest::from_rlp(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_huge_attacker_controlled_length_instead_of_panicking() {
+        // 0xbf declares an 8-byte big-endian length, set here to u64::MAX; decoding
+        // must return a clean error instead of overflowing the usize arithmetic that
+        // turns that length into a slice bound.
+        let mut malicious = vec![0xbfu8];
+        malicious.extend_from_slice(&u64::MAX.to_be_bytes());
+        assert!(decode(&malicious).is_err());
+    }
+
+    #[test]
+    fn test_group_proof_rlp_round_trip() {
+        let group_elements = vec![BaseElement::from(7u64), BaseElement::from(9u64)];
+        let merkle_path = vec![
+            ElementDigest::new([BaseElement::from(11u64); 4]),
+            ElementDigest::new([BaseElement::from(13u64); 4]),
+        ];
+
+        let encoded = encode_group_proof(&group_elements, &merkle_path);
+        assert!(matches!(decode(&encoded).unwrap(), Item::List(_)));
+
+        let (decoded_elements, decoded_path) = decode_group_proof(&encoded).unwrap();
+        assert_eq!(decoded_elements, group_elements);
+        assert_eq!(decoded_path, merkle_path);
+    }
+}
@@ -0,0 +1,119 @@
+use std::marker::PhantomData;
+
+use winter_crypto::Hasher;
+use winter_math::fields::f64::BaseElement;
+use winter_math::StarkField;
+
+use crate::digest_bn128::ElementDigest;
+use crate::linearhash_bn128::LinearHashBN128;
+
+/// A Fiat-Shamir transcript, generic over the Merkle `Hasher` backend `H` so the
+/// challenges it derives use the same hash the prover committed with.
+///
+/// Absorbing mixes new data into the running state via `H::merge`; squeezing derives
+/// the next challenge from the state via `H::merge_with_int`, advancing an internal
+/// counter so repeated squeezes without an intervening absorb still yield independent
+/// challenges. `grind` implements the proof-of-work step of query soundness: it
+/// searches for the smallest nonce whose challenge has enough leading zero bits.
+pub struct Transcript<H: Hasher<Digest = ElementDigest> = LinearHashBN128> {
+    state: ElementDigest,
+    counter: u64,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher<Digest = ElementDigest>> Default for Transcript<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Hasher<Digest = ElementDigest>> Transcript<H> {
+    pub fn new() -> Self {
+        Self {
+            state: ElementDigest::default(),
+            counter: 0,
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Absorbs a digest (e.g. a Merkle root) into the transcript state.
+    pub fn absorb_digest(&mut self, digest: ElementDigest) {
+        self.state = H::merge(&[self.state, digest]);
+        self.counter = 0;
+    }
+
+    /// Absorbs a slice of field elements into the transcript state.
+    pub fn absorb_elements(&mut self, elements: &[BaseElement]) {
+        let mut bytes = Vec::with_capacity(elements.len() * 8);
+        for e in elements.iter() {
+            bytes.extend_from_slice(&e.as_int().to_le_bytes());
+        }
+        self.absorb_digest(H::hash(&bytes));
+    }
+
+    /// Squeezes the next challenge digest out of the transcript.
+    pub fn squeeze(&mut self) -> ElementDigest {
+        let challenge = H::merge_with_int(self.state, self.counter);
+        self.counter += 1;
+        challenge
+    }
+
+    /// Squeezes the next challenge as a single field element.
+    pub fn squeeze_element(&mut self) -> BaseElement {
+        self.squeeze().as_elements()[0]
+    }
+
+    /// Searches for the smallest nonce such that `merge_with_int(state, nonce)`,
+    /// interpreting the resulting digest's first limb big-endian, has at least `bits`
+    /// leading zero bits. Does not consume the transcript's counter, so it can be
+    /// called at any point without perturbing subsequent `squeeze` calls.
+    pub fn grind(&self, bits: u32) -> u64 {
+        let mut nonce = 0u64;
+        loop {
+            if leading_zero_bits(&H::merge_with_int(self.state, nonce)) >= bits {
+                return nonce;
+            }
+            nonce += 1;
+        }
+    }
+
+    /// Cheaply rechecks a prover-supplied `grind` nonce against the current state.
+    pub fn verify_grind(&self, nonce: u64, bits: u32) -> bool {
+        leading_zero_bits(&H::merge_with_int(self.state, nonce)) >= bits
+    }
+}
+
+fn leading_zero_bits(digest: &ElementDigest) -> u32 {
+    digest.as_elements()[0].as_int().leading_zeros()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Transcript;
+    use crate::linearhash_bn128::LinearHashBN128;
+    use winter_math::fields::f64::BaseElement;
+
+    #[test]
+    fn test_squeeze_is_deterministic_and_advances() {
+        let mut t1 = Transcript::<LinearHashBN128>::new();
+        t1.absorb_elements(&[BaseElement::from(42u64)]);
+        let mut t2 = Transcript::<LinearHashBN128>::new();
+        t2.absorb_elements(&[BaseElement::from(42u64)]);
+
+        // two transcripts absorbing the same data squeeze the same challenge...
+        let first = t1.squeeze();
+        assert_eq!(first, t2.squeeze());
+        // ...but the second squeeze from the same transcript must differ from the first
+        assert_ne!(first, t1.squeeze());
+    }
+
+    #[test]
+    fn test_grind_is_reproducible_and_meets_target() {
+        let mut t = Transcript::<LinearHashBN128>::new();
+        t.absorb_elements(&[BaseElement::from(7u64)]);
+
+        let bits = 4;
+        let nonce = t.grind(bits);
+        assert!(t.verify_grind(nonce, bits));
+    }
+}